@@ -1,25 +1,42 @@
 pub use keyboard_types;
 
 use keyboard_types::Modifiers;
+use std::collections::HashMap;
+use std::time::Duration;
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+// The `serde` feature derives `Serialize`/`Deserialize` on every public
+// type in this crate, for recording and replaying event streams. It also
+// enables `keyboard_types`'s own `serde` feature, since `RawPointerEvent`
+// and `PointerEvent` embed a `Modifiers` value.
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PointerButton {
-    None = 0,
+    None,
 
     /// The primary pointer button, usually the left mouse button.
-    Primary = 1,
+    Primary,
 
     /// The secondary pointer bytton, usually the right mouse button.
-    Secondary = 2,
+    Secondary,
 
     /// The auxilary pointer button, usually the wheel or middle mouse button.
-    Auxiliary = 3,
+    Auxiliary,
 
     /// The fourth button, usually the back button.
-    X1 = 4,
+    X1,
 
     /// The fifth button, usually the forward button.
-    X2 = 5,
+    X2,
+
+    /// A button beyond `X2`, identified by its numeric index (6 and up),
+    /// for devices like gaming mice and pens with barrel buttons that
+    /// expose more buttons than the named variants cover.
+    ///
+    /// Indices below 6 are reserved for the named buttons above; a
+    /// `PointerButtons` set treats `Other(0..6)` as unrepresentable rather
+    /// than silently aliasing `Primary`..`X2`.
+    Other(u16),
 }
 
 impl From<isize> for PointerButton {
@@ -31,6 +48,7 @@ impl From<isize> for PointerButton {
             3 => PointerButton::Auxiliary,
             4 => PointerButton::X1,
             5 => PointerButton::X2,
+            n if n > 0 => PointerButton::Other(n.min(u16::MAX as isize) as u16),
             _ => PointerButton::None,
         }
     }
@@ -66,12 +84,62 @@ impl PointerButton {
     pub fn is_x2(self) -> bool {
         self == PointerButton::X2
     }
+
+    /// Returns `true` if this is `PointerButton::Other`.
+    #[inline]
+    pub fn is_other(self) -> bool {
+        matches!(self, PointerButton::Other(_))
+    }
+
+    /// The bit position this button occupies within a [`PointerButtons`]
+    /// set. `None` has no bit position of its own.
+    ///
+    /// `Other`'s numeric indices below 6 are reserved for the named
+    /// buttons above and can't be represented in the bitset; they map to
+    /// the same "no bit position" value as `None` rather than aliasing
+    /// `Primary`..`X2`.
+    #[inline]
+    fn bit_index(self) -> u16 {
+        match self {
+            PointerButton::None => 0,
+            PointerButton::Primary => 1,
+            PointerButton::Secondary => 2,
+            PointerButton::Auxiliary => 3,
+            PointerButton::X1 => 4,
+            PointerButton::X2 => 5,
+            PointerButton::Other(index) if index >= 6 => index,
+            PointerButton::Other(_) => 0,
+        }
+    }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Default)]
-pub struct PointerButtons(u8);
+/// A set of [`PointerButton`]s, stored as a bitset.
+///
+/// Buttons at indices 1 (`Primary`) through 63 round-trip through
+/// `insert`/`remove`/`contains`. `PointerButton::None` has no bit and is
+/// silently ignored, as is a [`PointerButton::Other`] index below 6 or
+/// beyond 63, since no known device exposes that many buttons.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointerButtons(u64);
 
 impl PointerButtons {
+    /// The highest button index that can be stored in the bitset.
+    const MAX_BIT_INDEX: u16 = 63;
+
+    /// Maps `button` to its bit mask, or `0` if it has no representable bit
+    /// (`PointerButton::None`, an `Other` index below 6, or an `Other` index
+    /// past the bitset's width).
+    #[inline]
+    fn bit_mask(button: PointerButton) -> u64 {
+        let index = button.bit_index();
+        if index == 0 || index > Self::MAX_BIT_INDEX {
+            0
+        } else {
+            1 << index
+        }
+    }
+
     /// Create a new empty set.
     #[inline]
     pub fn empty() -> PointerButtons {
@@ -81,33 +149,33 @@ impl PointerButtons {
     /// Add the `button` to the set.
     #[inline]
     pub fn insert(&mut self, button: PointerButton) {
-        self.0 |= 1.min(button as u8) << button as u8;
+        self.0 |= Self::bit_mask(button);
     }
 
     /// Remove the `button` from the set.
     #[inline]
     pub fn remove(&mut self, button: PointerButton) {
-        self.0 &= !(1.min(button as u8) << button as u8);
+        self.0 &= !Self::bit_mask(button);
     }
 
     /// Builder-style method for adding the `button` to the set.
     #[inline]
     pub fn with(mut self, button: PointerButton) -> PointerButtons {
-        self.0 |= 1.min(button as u8) << button as u8;
+        self.insert(button);
         self
     }
 
     /// Builder-style method for removing the `button` from the set.
     #[inline]
     pub fn without(mut self, button: PointerButton) -> PointerButtons {
-        self.0 &= !(1.min(button as u8) << button as u8);
+        self.remove(button);
         self
     }
 
     /// Returns `true` if the `button` is in the set.
     #[inline]
     pub fn contains(self, button: PointerButton) -> bool {
-        (self.0 & (1.min(button as u8) << button as u8)) != 0
+        (self.0 & Self::bit_mask(button)) != 0
     }
 
     /// Returns `true` if the set is empty.
@@ -172,38 +240,146 @@ impl PointerButtons {
     }
 }
 
-impl From<u8> for PointerButtons {
-    fn from(value: u8) -> Self {
+impl From<u64> for PointerButtons {
+    fn from(value: u64) -> Self {
         PointerButtons(value)
     }
 }
 
 impl std::fmt::Debug for PointerButtons {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "PointerButtons({:05b})", self.0 >> 1)
+        write!(f, "PointerButtons({:063b})", self.0 >> 1)
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PointerType {
     Mouse,
     Pen,
     Touch,
 }
 
+/// Identifies a single physical pointer, so that simultaneous contacts can
+/// be told apart.
+///
+/// `device` is a stable id for the physical mouse/pen/touchscreen, assigned
+/// by the backend. `slot` distinguishes individual contacts on a
+/// multi-touch device: it's `None` for mice and pens, which only ever have
+/// one active contact, and `Some` for touches, where each finger on the
+/// surface gets its own slot for the duration of its down/move/up sequence.
+/// Use [`TouchSlots`] to allocate and recycle slot indices as touches begin
+/// and end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointerId {
+    /// A stable id for the physical device that produced the event.
+    pub device: u64,
+
+    /// The touch slot, or `None` for a mouse or pen.
+    pub slot: Option<u32>,
+}
+
+impl PointerId {
+    /// Creates the id for a mouse or pen, which has no touch slot.
+    pub fn device(device: u64) -> PointerId {
+        PointerId { device, slot: None }
+    }
+
+    /// Creates the id for a single touch contact on `device`.
+    pub fn touch(device: u64, slot: u32) -> PointerId {
+        PointerId {
+            device,
+            slot: Some(slot),
+        }
+    }
+}
+
+/// Allocates and recycles touch slot indices for a single touch device.
+///
+/// Call [`TouchSlots::begin`] when a finger touches down to get a fresh
+/// slot index, and [`TouchSlots::end`] when it lifts off to make that index
+/// available for reuse, mirroring smithay's `TouchSlot` allocation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TouchSlots {
+    recycled: Vec<u32>,
+    next: u32,
+}
+
+impl TouchSlots {
+    /// Creates an empty allocator.
+    pub fn new() -> TouchSlots {
+        TouchSlots::default()
+    }
+
+    /// Allocates a slot index for a new touch contact, reusing a recycled
+    /// index if one is available.
+    pub fn begin(&mut self) -> u32 {
+        if let Some(slot) = self.recycled.pop() {
+            slot
+        } else {
+            let slot = self.next;
+            self.next += 1;
+            slot
+        }
+    }
+
+    /// Returns `slot` to the pool so it can be reused by a future contact.
+    pub fn end(&mut self, slot: u32) {
+        self.recycled.push(slot);
+    }
+}
+
+/// Groups `events` by the [`PointerId`] that produced them, preserving the
+/// relative order of events with the same id.
+///
+/// Useful for tracking per-contact state (e.g. for pinch/rotate gestures)
+/// across a batch of events from multiple simultaneous pointers.
+pub fn group_by_pointer_id<I>(events: I) -> HashMap<PointerId, Vec<RawPointerEvent>>
+where
+    I: IntoIterator<Item = RawPointerEvent>,
+{
+    let mut groups: HashMap<PointerId, Vec<RawPointerEvent>> = HashMap::new();
+    for event in events {
+        groups.entry(event.pointer_id).or_default().push(event);
+    }
+    groups
+}
+
+/// The kind of event a [`RawPointerEvent`] represents, set by the backend
+/// that produced it.
+///
+/// This is what lets [`TryFrom<RawPointerEvent>`](TryFrom) for
+/// [`PointerEvent`] classify a raw event deterministically instead of
+/// guessing from its other fields, and what lets the round trip back
+/// through [`From<PointerEvent>`](From) preserve `Enter`/`Leave`/`Cancel`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RawPointerEventKind {
+    Down,
+    Up,
+    Move,
+    Scroll,
+    Enter,
+    Leave,
+    Cancel,
+}
+
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RawPointerEvent {
+    /// The kind of event this raw payload represents.
+    pub kind: RawPointerEventKind,
+
     /// The horizontal coordinate of the pointer event in the window.
     pub window_pos_x: f64,
 
     /// The vertical coordinate of the pointer event in the window.
     pub window_pos_y: f64,
 
-    /// The horizontal scroll amount.
-    pub wheel_x: f64,
-
-    /// The vertical scroll amount.
-    pub wheel_y: f64,
+    /// The scroll amount, along with its source and unit.
+    pub scroll: ScrollDelta,
 
     /// The button responsible for a pointer event.
     /// This will always be `None` for a pointer_move event.
@@ -250,4 +426,1061 @@ pub struct RawPointerEvent {
 
     /// Indicates the device type that caused the event.
     pub pointer_type: PointerType,
+
+    /// A monotonic timestamp for the event, supplied by the backend.
+    ///
+    /// This is not tied to any particular epoch; only differences between
+    /// two `time` values are meaningful. It is used for timing-sensitive
+    /// gestures such as multi-click detection (see [`ClickCounter`]) and
+    /// inertial scrolling.
+    pub time: Duration,
+
+    /// Identifies which physical pointer produced this event, so that
+    /// simultaneous contacts (e.g. multi-touch) can be told apart.
+    pub pointer_id: PointerId,
+}
+
+impl RawPointerEvent {
+    /// Returns the event's monotonic timestamp.
+    #[inline]
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Returns the horizontal scroll amount as a bare float, for callers
+    /// that don't need [`ScrollDelta`]'s source/unit information.
+    #[inline]
+    pub fn wheel_x(&self) -> f64 {
+        self.scroll.x
+    }
+
+    /// Returns the vertical scroll amount as a bare float, for callers
+    /// that don't need [`ScrollDelta`]'s source/unit information.
+    #[inline]
+    pub fn wheel_y(&self) -> f64 {
+        self.scroll.y
+    }
+}
+
+/// The device or interaction that produced a [`ScrollDelta`].
+///
+/// Mirrors smithay's `AxisSource`: mouse wheels are notched and should snap
+/// to discrete steps, while trackpads and other continuous sources should
+/// scroll smoothly at pixel precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollSource {
+    /// A traditional notched mouse wheel.
+    Wheel,
+
+    /// A notched wheel that tilts sideways for horizontal scrolling.
+    WheelTilt,
+
+    /// A finger on a touchpad or touchscreen.
+    Finger,
+
+    /// A continuous, high-resolution source with no discrete steps.
+    Continuous,
+}
+
+/// The unit a [`ScrollDelta`]'s `x`/`y` amounts are expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollUnit {
+    /// A number of wheel notches/lines of text.
+    Lines,
+
+    /// A number of logical pixels.
+    Pixels,
+}
+
+/// A scroll amount, tagged with the unit it's expressed in and the kind of
+/// device that produced it.
+///
+/// Knowing the [`ScrollSource`] lets UI code apply wheel-detent snapping for
+/// [`ScrollSource::Wheel`]/[`ScrollSource::WheelTilt`] input while scrolling
+/// smoothly, at pixel precision, for [`ScrollSource::Finger`] and
+/// [`ScrollSource::Continuous`] input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollDelta {
+    /// The horizontal scroll amount, in `unit`s.
+    pub x: f64,
+
+    /// The vertical scroll amount, in `unit`s.
+    pub y: f64,
+
+    /// The unit `x` and `y` are expressed in.
+    pub unit: ScrollUnit,
+
+    /// The device or interaction that produced this delta.
+    pub source: ScrollSource,
+}
+
+impl Default for ScrollDelta {
+    fn default() -> Self {
+        ScrollDelta {
+            x: 0.0,
+            y: 0.0,
+            unit: ScrollUnit::Pixels,
+            source: ScrollSource::Wheel,
+        }
+    }
+}
+
+impl From<ScrollDelta> for (f64, f64) {
+    /// Discards the unit and source, for callers that only want the raw
+    /// `(x, y)` amount.
+    fn from(delta: ScrollDelta) -> Self {
+        (delta.x, delta.y)
+    }
+}
+
+/// Pen/touch contact geometry and pressure, carried by [`PointerEvent`]'s
+/// `Down`/`Up`/`Move` variants. See the corresponding fields on
+/// [`RawPointerEvent`] for what each one means; it's all zeroed out for
+/// devices that don't report it (e.g. a plain mouse).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactGeometry {
+    pub width: u16,
+    pub height: u16,
+    pub pressure: f32,
+    pub tangential_pressure: f32,
+    pub tilt_x: f64,
+    pub tilt_y: f64,
+    pub twist: f32,
+}
+
+impl Default for ContactGeometry {
+    fn default() -> Self {
+        ContactGeometry {
+            width: 0,
+            height: 0,
+            pressure: 0.0,
+            tangential_pressure: 0.0,
+            tilt_x: 0.0,
+            tilt_y: 0.0,
+            twist: 0.0,
+        }
+    }
+}
+
+impl ContactGeometry {
+    fn from_raw(raw: &RawPointerEvent) -> ContactGeometry {
+        ContactGeometry {
+            width: raw.width,
+            height: raw.height,
+            pressure: raw.pressure,
+            tangential_pressure: raw.tangential_pressure,
+            tilt_x: raw.tilt_x,
+            tilt_y: raw.tilt_y,
+            twist: raw.twist,
+        }
+    }
+
+    fn write_to_raw(self, raw: &mut RawPointerEvent) {
+        raw.width = self.width;
+        raw.height = self.height;
+        raw.pressure = self.pressure;
+        raw.tangential_pressure = self.tangential_pressure;
+        raw.tilt_x = self.tilt_x;
+        raw.tilt_y = self.tilt_y;
+        raw.twist = self.twist;
+    }
+}
+
+/// A pointer event, classified by kind, carrying only the fields that are
+/// meaningful for that kind.
+///
+/// [`RawPointerEvent`] packs every possible field into one struct, so
+/// consumers have to remember which fields are garbage for a given event
+/// (e.g. `button` is always `None` for a move). `PointerEvent` instead gives
+/// each kind its own variant with only the applicable fields.
+///
+/// Backends that already build a [`RawPointerEvent`] can classify it with
+/// [`TryFrom`], and any `PointerEvent` can be converted back into a
+/// [`RawPointerEvent`] with [`From`] for code that still wants the flat
+/// representation.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointerEvent {
+    /// A pointer button was pressed.
+    Down {
+        window_pos_x: f64,
+        window_pos_y: f64,
+        button: PointerButton,
+        buttons: PointerButtons,
+        mods: Modifiers,
+        /// The number of clicks associated with this press (see
+        /// [`RawPointerEvent::count`]).
+        count: u8,
+        /// Pen/touch contact geometry and pressure, if applicable.
+        contact: ContactGeometry,
+        pointer_type: PointerType,
+        time: Duration,
+        pointer_id: PointerId,
+    },
+
+    /// A pointer button was released.
+    Up {
+        window_pos_x: f64,
+        window_pos_y: f64,
+        button: PointerButton,
+        buttons: PointerButtons,
+        mods: Modifiers,
+        /// Pen/touch contact geometry and pressure, if applicable.
+        contact: ContactGeometry,
+        pointer_type: PointerType,
+        time: Duration,
+        pointer_id: PointerId,
+    },
+
+    /// The pointer moved, with no change in button state.
+    Move {
+        window_pos_x: f64,
+        window_pos_y: f64,
+        buttons: PointerButtons,
+        mods: Modifiers,
+        /// Pen/touch contact geometry and pressure, if applicable.
+        contact: ContactGeometry,
+        pointer_type: PointerType,
+        time: Duration,
+        pointer_id: PointerId,
+    },
+
+    /// The pointer's wheel was scrolled.
+    Scroll {
+        window_pos_x: f64,
+        window_pos_y: f64,
+        delta: ScrollDelta,
+        mods: Modifiers,
+        pointer_type: PointerType,
+        time: Duration,
+        pointer_id: PointerId,
+    },
+
+    /// The pointer entered the window, optionally gaining focus for it.
+    Enter {
+        window_pos_x: f64,
+        window_pos_y: f64,
+        focus: bool,
+        pointer_type: PointerType,
+        time: Duration,
+        pointer_id: PointerId,
+    },
+
+    /// The pointer left the window.
+    Leave {
+        pointer_type: PointerType,
+        time: Duration,
+        pointer_id: PointerId,
+    },
+
+    /// The pointer's gesture was cancelled by the platform (e.g. a touch
+    /// was claimed by system-level gesture handling).
+    Cancel {
+        pointer_type: PointerType,
+        time: Duration,
+        pointer_id: PointerId,
+    },
+}
+
+/// The [`RawPointerEvent`]'s fields were inconsistent with its declared
+/// [`RawPointerEventKind`], so it could not be classified into a
+/// [`PointerEvent`].
+///
+/// For example, `kind: RawPointerEventKind::Up` requires `button` to be set
+/// to the button that was released, and `kind: RawPointerEventKind::Scroll`
+/// requires a non-zero scroll delta; a backend that doesn't supply these
+/// produces a raw event that genuinely can't be classified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnclassifiableEvent;
+
+impl std::fmt::Display for UnclassifiableEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "raw pointer event does not carry enough information to classify")
+    }
+}
+
+impl std::error::Error for UnclassifiableEvent {}
+
+impl TryFrom<RawPointerEvent> for PointerEvent {
+    type Error = UnclassifiableEvent;
+
+    /// Classifies a [`RawPointerEvent`] according to its declared
+    /// [`RawPointerEvent::kind`], validating that the other fields are
+    /// coherent with it.
+    fn try_from(raw: RawPointerEvent) -> Result<Self, Self::Error> {
+        match raw.kind {
+            RawPointerEventKind::Down => Ok(PointerEvent::Down {
+                window_pos_x: raw.window_pos_x,
+                window_pos_y: raw.window_pos_y,
+                button: raw.button,
+                buttons: raw.buttons,
+                mods: raw.mods,
+                // `count` is 0 for backends that don't run their presses
+                // through a `ClickCounter` (see its docs); `kind` alone is
+                // enough to know this is a genuine press either way.
+                count: raw.count,
+                contact: ContactGeometry::from_raw(&raw),
+                pointer_type: raw.pointer_type,
+                time: raw.time,
+                pointer_id: raw.pointer_id,
+            }),
+            RawPointerEventKind::Up if raw.button != PointerButton::None => Ok(PointerEvent::Up {
+                window_pos_x: raw.window_pos_x,
+                window_pos_y: raw.window_pos_y,
+                button: raw.button,
+                buttons: raw.buttons,
+                mods: raw.mods,
+                contact: ContactGeometry::from_raw(&raw),
+                pointer_type: raw.pointer_type,
+                time: raw.time,
+                pointer_id: raw.pointer_id,
+            }),
+            RawPointerEventKind::Move => Ok(PointerEvent::Move {
+                window_pos_x: raw.window_pos_x,
+                window_pos_y: raw.window_pos_y,
+                buttons: raw.buttons,
+                mods: raw.mods,
+                contact: ContactGeometry::from_raw(&raw),
+                pointer_type: raw.pointer_type,
+                time: raw.time,
+                pointer_id: raw.pointer_id,
+            }),
+            RawPointerEventKind::Scroll if raw.scroll.x != 0.0 || raw.scroll.y != 0.0 => {
+                Ok(PointerEvent::Scroll {
+                    window_pos_x: raw.window_pos_x,
+                    window_pos_y: raw.window_pos_y,
+                    delta: raw.scroll,
+                    mods: raw.mods,
+                    pointer_type: raw.pointer_type,
+                    time: raw.time,
+                    pointer_id: raw.pointer_id,
+                })
+            }
+            RawPointerEventKind::Enter => Ok(PointerEvent::Enter {
+                window_pos_x: raw.window_pos_x,
+                window_pos_y: raw.window_pos_y,
+                focus: raw.focus,
+                pointer_type: raw.pointer_type,
+                time: raw.time,
+                pointer_id: raw.pointer_id,
+            }),
+            RawPointerEventKind::Leave => Ok(PointerEvent::Leave {
+                pointer_type: raw.pointer_type,
+                time: raw.time,
+                pointer_id: raw.pointer_id,
+            }),
+            RawPointerEventKind::Cancel => Ok(PointerEvent::Cancel {
+                pointer_type: raw.pointer_type,
+                time: raw.time,
+                pointer_id: raw.pointer_id,
+            }),
+            RawPointerEventKind::Up | RawPointerEventKind::Scroll => Err(UnclassifiableEvent),
+        }
+    }
+}
+
+impl From<PointerEvent> for RawPointerEvent {
+    /// Rebuilds a flat [`RawPointerEvent`], filling in the fields that don't
+    /// apply to `event`'s kind with their neutral defaults.
+    fn from(event: PointerEvent) -> Self {
+        let mut raw = RawPointerEvent {
+            window_pos_x: 0.0,
+            window_pos_y: 0.0,
+            scroll: ScrollDelta::default(),
+            button: PointerButton::None,
+            buttons: PointerButtons::empty(),
+            mods: Modifiers::empty(),
+            count: 0,
+            focus: false,
+            width: 0,
+            height: 0,
+            pressure: 0.0,
+            tangential_pressure: 0.0,
+            tilt_x: 0.0,
+            tilt_y: 0.0,
+            twist: 0.0,
+            pointer_type: PointerType::Mouse,
+            time: Duration::ZERO,
+            pointer_id: PointerId::device(0),
+            kind: RawPointerEventKind::Move,
+        };
+
+        match event {
+            PointerEvent::Down {
+                window_pos_x,
+                window_pos_y,
+                button,
+                buttons,
+                mods,
+                count,
+                contact,
+                pointer_type,
+                time,
+                pointer_id,
+            } => {
+                raw.window_pos_x = window_pos_x;
+                raw.window_pos_y = window_pos_y;
+                raw.button = button;
+                raw.buttons = buttons;
+                raw.mods = mods;
+                raw.count = count;
+                contact.write_to_raw(&mut raw);
+                raw.pointer_type = pointer_type;
+                raw.time = time;
+                raw.pointer_id = pointer_id;
+                raw.kind = RawPointerEventKind::Down;
+            }
+            PointerEvent::Up {
+                window_pos_x,
+                window_pos_y,
+                button,
+                buttons,
+                mods,
+                contact,
+                pointer_type,
+                time,
+                pointer_id,
+            } => {
+                raw.window_pos_x = window_pos_x;
+                raw.window_pos_y = window_pos_y;
+                raw.button = button;
+                raw.buttons = buttons;
+                raw.mods = mods;
+                contact.write_to_raw(&mut raw);
+                raw.pointer_type = pointer_type;
+                raw.time = time;
+                raw.pointer_id = pointer_id;
+                raw.kind = RawPointerEventKind::Up;
+            }
+            PointerEvent::Move {
+                window_pos_x,
+                window_pos_y,
+                buttons,
+                mods,
+                contact,
+                pointer_type,
+                time,
+                pointer_id,
+            } => {
+                raw.window_pos_x = window_pos_x;
+                raw.window_pos_y = window_pos_y;
+                raw.buttons = buttons;
+                raw.mods = mods;
+                contact.write_to_raw(&mut raw);
+                raw.pointer_type = pointer_type;
+                raw.time = time;
+                raw.pointer_id = pointer_id;
+                raw.kind = RawPointerEventKind::Move;
+            }
+            PointerEvent::Scroll {
+                window_pos_x,
+                window_pos_y,
+                delta,
+                mods,
+                pointer_type,
+                time,
+                pointer_id,
+            } => {
+                raw.window_pos_x = window_pos_x;
+                raw.window_pos_y = window_pos_y;
+                raw.scroll = delta;
+                raw.mods = mods;
+                raw.pointer_type = pointer_type;
+                raw.time = time;
+                raw.pointer_id = pointer_id;
+                raw.kind = RawPointerEventKind::Scroll;
+            }
+            PointerEvent::Enter {
+                window_pos_x,
+                window_pos_y,
+                focus,
+                pointer_type,
+                time,
+                pointer_id,
+            } => {
+                raw.window_pos_x = window_pos_x;
+                raw.window_pos_y = window_pos_y;
+                raw.focus = focus;
+                raw.pointer_type = pointer_type;
+                raw.time = time;
+                raw.pointer_id = pointer_id;
+                raw.kind = RawPointerEventKind::Enter;
+            }
+            PointerEvent::Leave {
+                pointer_type,
+                time,
+                pointer_id,
+            } => {
+                raw.pointer_type = pointer_type;
+                raw.time = time;
+                raw.pointer_id = pointer_id;
+                raw.kind = RawPointerEventKind::Leave;
+            }
+            PointerEvent::Cancel {
+                pointer_type,
+                time,
+                pointer_id,
+            } => {
+                raw.pointer_type = pointer_type;
+                raw.time = time;
+                raw.pointer_id = pointer_id;
+                raw.kind = RawPointerEventKind::Cancel;
+            }
+        }
+
+        raw
+    }
+}
+
+/// Thresholds used by [`ClickCounter`] to decide whether a press continues a
+/// multi-click sequence, and whether a gesture is a click or a drag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClickConfig {
+    /// The maximum distance, in logical pixels, the pointer may have moved
+    /// between a release and the next press (or away from a press) for the
+    /// gesture to still count as a click.
+    pub max_click_distance: f64,
+
+    /// The maximum time between a release and the next press for the next
+    /// press to continue the click count instead of resetting it.
+    pub max_click_delay: Duration,
+}
+
+impl Default for ClickConfig {
+    fn default() -> Self {
+        ClickConfig {
+            max_click_distance: 6.0,
+            max_click_delay: Duration::from_millis(300),
+        }
+    }
+}
+
+/// Tracks multi-click counts and click-vs-drag state for a single pointer.
+///
+/// Feed it `on_down`/`on_move`/`on_up` as events arrive, along with a
+/// monotonic timestamp for each. A new press continues the previous click
+/// count only if it lands within [`ClickConfig::max_click_distance`] and
+/// [`ClickConfig::max_click_delay`] of the last release; otherwise the count
+/// resets to 1. Once the pointer strays past `max_click_distance` while a
+/// button is held, the gesture is a drag and [`ClickCounter::is_drag`]
+/// returns `true` for the remainder of the press.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClickCounter {
+    config: ClickConfig,
+    press_pos: Option<(f64, f64)>,
+    last_release: Option<((f64, f64), Duration)>,
+    count: u8,
+    dragging: bool,
+}
+
+impl ClickCounter {
+    /// Creates a new counter using the default [`ClickConfig`].
+    pub fn new() -> Self {
+        Self::with_config(ClickConfig::default())
+    }
+
+    /// Creates a new counter using the given thresholds.
+    pub fn with_config(config: ClickConfig) -> Self {
+        ClickCounter {
+            config,
+            press_pos: None,
+            last_release: None,
+            count: 0,
+            dragging: false,
+        }
+    }
+
+    /// Records a pointer-down at `pos` and `time`, returning the updated
+    /// click count.
+    pub fn on_down(&mut self, pos: (f64, f64), time: Duration) -> u8 {
+        let continues_sequence = match self.last_release {
+            Some((release_pos, release_time)) => {
+                distance(release_pos, pos) <= self.config.max_click_distance
+                    && time.saturating_sub(release_time) <= self.config.max_click_delay
+            }
+            None => false,
+        };
+
+        self.count = if continues_sequence { self.count + 1 } else { 1 };
+        self.press_pos = Some(pos);
+        self.dragging = false;
+        self.count
+    }
+
+    /// Records pointer movement at `pos` while a button is held, which may
+    /// promote the current gesture to a drag.
+    pub fn on_move(&mut self, pos: (f64, f64)) {
+        if let Some(press_pos) = self.press_pos {
+            if !self.dragging && distance(press_pos, pos) > self.config.max_click_distance {
+                self.dragging = true;
+            }
+        }
+    }
+
+    /// Records a pointer-up at `pos` and `time`, returning `true` if the
+    /// gesture was a click (as opposed to a drag).
+    pub fn on_up(&mut self, pos: (f64, f64), time: Duration) -> bool {
+        let was_click = !self.dragging;
+        self.last_release = Some((pos, time));
+        self.press_pos = None;
+        was_click
+    }
+
+    /// The current click count, as last reported by [`ClickCounter::on_down`].
+    pub fn count(&self) -> u8 {
+        self.count
+    }
+
+    /// Returns `true` if the pointer has moved far enough during the
+    /// current press for the gesture to be a drag rather than a click.
+    pub fn is_drag(&self) -> bool {
+        self.dragging
+    }
+}
+
+impl Default for ClickCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let dx = a.0 - b.0;
+    let dy = a.1 - b.1;
+    (dx * dx + dy * dy).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn other_below_six_does_not_alias_named_buttons() {
+        let mut buttons = PointerButtons::empty();
+        buttons.insert(PointerButton::Other(2));
+        assert!(!buttons.contains(PointerButton::Secondary));
+        assert!(!buttons.contains(PointerButton::Other(2)));
+        assert!(buttons.is_empty());
+    }
+
+    #[test]
+    fn other_zero_is_a_no_op() {
+        let mut buttons = PointerButtons::empty();
+        buttons.insert(PointerButton::Other(0));
+        assert!(buttons.is_empty());
+        assert!(!buttons.contains(PointerButton::None));
+    }
+
+    #[test]
+    fn other_six_is_distinct_from_named_buttons() {
+        let buttons = PointerButtons::empty().with(PointerButton::Other(6));
+        assert!(buttons.contains(PointerButton::Other(6)));
+        assert!(!buttons.contains(PointerButton::Primary));
+        assert!(!buttons.contains(PointerButton::Secondary));
+        assert!(!buttons.contains(PointerButton::Auxiliary));
+        assert!(!buttons.contains(PointerButton::X1));
+        assert!(!buttons.contains(PointerButton::X2));
+    }
+
+    #[test]
+    fn other_at_max_bit_index_round_trips() {
+        let mut buttons = PointerButtons::empty();
+        buttons.insert(PointerButton::Other(63));
+        assert!(buttons.contains(PointerButton::Other(63)));
+        buttons.remove(PointerButton::Other(63));
+        assert!(buttons.is_empty());
+    }
+
+    #[test]
+    fn other_beyond_bitset_width_is_ignored() {
+        let mut buttons = PointerButtons::empty();
+        buttons.insert(PointerButton::Other(64));
+        assert!(buttons.is_empty());
+        assert!(!buttons.contains(PointerButton::Other(64)));
+
+        buttons.insert(PointerButton::Other(u16::MAX));
+        assert!(buttons.is_empty());
+    }
+
+    #[test]
+    fn touch_slots_recycles_a_freed_slot_before_a_new_one() {
+        let mut slots = TouchSlots::new();
+        let first = slots.begin();
+        let second = slots.begin();
+        assert_ne!(first, second);
+
+        slots.end(first);
+        assert_eq!(slots.begin(), first);
+        assert_eq!(slots.begin(), second + 1);
+    }
+
+    #[test]
+    fn group_by_pointer_id_preserves_per_id_order() {
+        let a = PointerId::device(1);
+        let b = PointerId::device(2);
+        let events = vec![
+            RawPointerEvent {
+                window_pos_x: 1.0,
+                pointer_id: a,
+                ..raw_event(RawPointerEventKind::Move)
+            },
+            RawPointerEvent {
+                window_pos_x: 2.0,
+                pointer_id: b,
+                ..raw_event(RawPointerEventKind::Move)
+            },
+            RawPointerEvent {
+                window_pos_x: 3.0,
+                pointer_id: a,
+                ..raw_event(RawPointerEventKind::Move)
+            },
+        ];
+
+        let groups = group_by_pointer_id(events);
+        let a_positions: Vec<f64> = groups[&a].iter().map(|e| e.window_pos_x).collect();
+        let b_positions: Vec<f64> = groups[&b].iter().map(|e| e.window_pos_x).collect();
+        assert_eq!(a_positions, vec![1.0, 3.0]);
+        assert_eq!(b_positions, vec![2.0]);
+    }
+
+    #[test]
+    fn scroll_delta_default_is_zeroed_pixels_from_a_wheel() {
+        let delta = ScrollDelta::default();
+        assert_eq!(delta.x, 0.0);
+        assert_eq!(delta.y, 0.0);
+        assert_eq!(delta.unit, ScrollUnit::Pixels);
+        assert_eq!(delta.source, ScrollSource::Wheel);
+    }
+
+    #[test]
+    fn scroll_delta_converts_to_a_bare_tuple() {
+        let delta = ScrollDelta {
+            x: 1.5,
+            y: -2.5,
+            unit: ScrollUnit::Lines,
+            source: ScrollSource::Finger,
+        };
+        assert_eq!(<(f64, f64)>::from(delta), (1.5, -2.5));
+    }
+
+    #[test]
+    fn wheel_x_and_wheel_y_read_through_to_scroll() {
+        let raw = RawPointerEvent {
+            scroll: ScrollDelta {
+                x: 3.0,
+                y: 4.0,
+                ..ScrollDelta::default()
+            },
+            ..raw_event(RawPointerEventKind::Scroll)
+        };
+        assert_eq!(raw.wheel_x(), 3.0);
+        assert_eq!(raw.wheel_y(), 4.0);
+    }
+
+    fn raw_event(kind: RawPointerEventKind) -> RawPointerEvent {
+        RawPointerEvent {
+            kind,
+            window_pos_x: 0.0,
+            window_pos_y: 0.0,
+            scroll: ScrollDelta::default(),
+            button: PointerButton::None,
+            buttons: PointerButtons::empty(),
+            mods: Modifiers::empty(),
+            count: 0,
+            focus: false,
+            width: 0,
+            height: 0,
+            pressure: 0.0,
+            tangential_pressure: 0.0,
+            tilt_x: 0.0,
+            tilt_y: 0.0,
+            twist: 0.0,
+            pointer_type: PointerType::Mouse,
+            time: Duration::ZERO,
+            pointer_id: PointerId::device(0),
+        }
+    }
+
+    #[test]
+    fn down_with_zero_count_still_classifies() {
+        let raw = raw_event(RawPointerEventKind::Down);
+        assert!(matches!(PointerEvent::try_from(raw), Ok(PointerEvent::Down { count: 0, .. })));
+    }
+
+    #[test]
+    fn up_without_a_button_is_unclassifiable() {
+        let raw = raw_event(RawPointerEventKind::Up);
+        assert!(matches!(PointerEvent::try_from(raw), Err(UnclassifiableEvent)));
+    }
+
+    #[test]
+    fn scroll_with_zero_delta_is_unclassifiable() {
+        let raw = raw_event(RawPointerEventKind::Scroll);
+        assert!(matches!(PointerEvent::try_from(raw), Err(UnclassifiableEvent)));
+    }
+
+    #[test]
+    fn enter_leave_cancel_round_trip_through_raw() {
+        for kind in [
+            RawPointerEventKind::Enter,
+            RawPointerEventKind::Leave,
+            RawPointerEventKind::Cancel,
+        ] {
+            let raw = raw_event(kind);
+            let event = PointerEvent::try_from(raw).expect("enter/leave/cancel always classify");
+            let round_tripped = RawPointerEvent::from(event);
+            assert_eq!(round_tripped.kind, kind);
+        }
+    }
+
+    #[test]
+    fn down_classifies_and_round_trips_real_fields() {
+        let raw = RawPointerEvent {
+            window_pos_x: 12.5,
+            window_pos_y: 34.5,
+            button: PointerButton::Primary,
+            buttons: PointerButtons::empty().with(PointerButton::Primary),
+            mods: Modifiers::SHIFT,
+            count: 2,
+            width: 10,
+            height: 12,
+            pressure: 0.5,
+            tangential_pressure: 0.1,
+            tilt_x: 0.2,
+            tilt_y: -0.3,
+            twist: 90.0,
+            pointer_type: PointerType::Pen,
+            time: Duration::from_millis(42),
+            pointer_id: PointerId::device(7),
+            ..raw_event(RawPointerEventKind::Down)
+        };
+
+        let event = PointerEvent::try_from(raw).expect("well-formed down always classifies");
+        match event {
+            PointerEvent::Down {
+                window_pos_x,
+                window_pos_y,
+                button,
+                buttons,
+                mods,
+                count,
+                contact,
+                pointer_type,
+                time,
+                pointer_id,
+            } => {
+                assert_eq!(window_pos_x, 12.5);
+                assert_eq!(window_pos_y, 34.5);
+                assert_eq!(button, PointerButton::Primary);
+                assert!(buttons.has_primary());
+                assert_eq!(mods, Modifiers::SHIFT);
+                assert_eq!(count, 2);
+                assert_eq!(contact.width, 10);
+                assert_eq!(contact.pressure, 0.5);
+                assert_eq!(contact.tilt_x, 0.2);
+                assert_eq!(pointer_type, PointerType::Pen);
+                assert_eq!(time, Duration::from_millis(42));
+                assert_eq!(pointer_id, PointerId::device(7));
+            }
+            _ => panic!("expected PointerEvent::Down"),
+        }
+
+        let round_tripped = RawPointerEvent::from(event);
+        assert_eq!(round_tripped.kind, RawPointerEventKind::Down);
+        assert_eq!(round_tripped.window_pos_x, 12.5);
+        assert_eq!(round_tripped.button, PointerButton::Primary);
+        assert_eq!(round_tripped.count, 2);
+        assert_eq!(round_tripped.width, 10);
+        assert_eq!(round_tripped.pressure, 0.5);
+        assert_eq!(round_tripped.pointer_id, PointerId::device(7));
+    }
+
+    #[test]
+    fn up_classifies_and_round_trips_real_fields() {
+        let raw = RawPointerEvent {
+            window_pos_x: 1.0,
+            window_pos_y: 2.0,
+            button: PointerButton::Secondary,
+            buttons: PointerButtons::empty(),
+            mods: Modifiers::CONTROL,
+            width: 8,
+            pressure: 0.25,
+            pointer_type: PointerType::Touch,
+            time: Duration::from_millis(99),
+            pointer_id: PointerId::touch(3, 1),
+            ..raw_event(RawPointerEventKind::Up)
+        };
+
+        let event = PointerEvent::try_from(raw).expect("well-formed up always classifies");
+        match event {
+            PointerEvent::Up {
+                window_pos_x,
+                button,
+                buttons,
+                mods,
+                contact,
+                pointer_type,
+                pointer_id,
+                ..
+            } => {
+                assert_eq!(window_pos_x, 1.0);
+                assert_eq!(button, PointerButton::Secondary);
+                assert!(buttons.is_empty());
+                assert_eq!(mods, Modifiers::CONTROL);
+                assert_eq!(contact.width, 8);
+                assert_eq!(contact.pressure, 0.25);
+                assert_eq!(pointer_type, PointerType::Touch);
+                assert_eq!(pointer_id, PointerId::touch(3, 1));
+            }
+            _ => panic!("expected PointerEvent::Up"),
+        }
+
+        let round_tripped = RawPointerEvent::from(event);
+        assert_eq!(round_tripped.kind, RawPointerEventKind::Up);
+        assert_eq!(round_tripped.button, PointerButton::Secondary);
+        assert_eq!(round_tripped.width, 8);
+        assert_eq!(round_tripped.pointer_id, PointerId::touch(3, 1));
+    }
+
+    #[test]
+    fn move_classifies_and_round_trips_real_fields() {
+        let raw = RawPointerEvent {
+            window_pos_x: 5.0,
+            window_pos_y: 6.0,
+            buttons: PointerButtons::empty().with(PointerButton::Primary),
+            mods: Modifiers::ALT,
+            tilt_x: 0.4,
+            tilt_y: 0.5,
+            twist: 12.0,
+            pointer_type: PointerType::Pen,
+            time: Duration::from_millis(7),
+            pointer_id: PointerId::device(2),
+            ..raw_event(RawPointerEventKind::Move)
+        };
+
+        let event = PointerEvent::try_from(raw).expect("move always classifies");
+        match event {
+            PointerEvent::Move {
+                window_pos_x,
+                buttons,
+                mods,
+                contact,
+                pointer_type,
+                pointer_id,
+                ..
+            } => {
+                assert_eq!(window_pos_x, 5.0);
+                assert!(buttons.has_primary());
+                assert_eq!(mods, Modifiers::ALT);
+                assert_eq!(contact.tilt_x, 0.4);
+                assert_eq!(contact.tilt_y, 0.5);
+                assert_eq!(contact.twist, 12.0);
+                assert_eq!(pointer_type, PointerType::Pen);
+                assert_eq!(pointer_id, PointerId::device(2));
+            }
+            _ => panic!("expected PointerEvent::Move"),
+        }
+
+        let round_tripped = RawPointerEvent::from(event);
+        assert_eq!(round_tripped.kind, RawPointerEventKind::Move);
+        assert!(round_tripped.buttons.has_primary());
+        assert_eq!(round_tripped.tilt_x, 0.4);
+        assert_eq!(round_tripped.pointer_id, PointerId::device(2));
+    }
+
+    #[test]
+    fn scroll_classifies_and_round_trips_real_fields() {
+        let delta = ScrollDelta {
+            x: 0.0,
+            y: -3.0,
+            unit: ScrollUnit::Lines,
+            source: ScrollSource::Wheel,
+        };
+        let raw = RawPointerEvent {
+            window_pos_x: 9.0,
+            window_pos_y: 10.0,
+            scroll: delta,
+            mods: Modifiers::empty(),
+            pointer_type: PointerType::Mouse,
+            time: Duration::from_millis(3),
+            pointer_id: PointerId::device(1),
+            ..raw_event(RawPointerEventKind::Scroll)
+        };
+
+        let event = PointerEvent::try_from(raw).expect("non-zero scroll always classifies");
+        match event {
+            PointerEvent::Scroll {
+                window_pos_x,
+                delta: classified_delta,
+                pointer_type,
+                pointer_id,
+                ..
+            } => {
+                assert_eq!(window_pos_x, 9.0);
+                assert_eq!(classified_delta, delta);
+                assert_eq!(pointer_type, PointerType::Mouse);
+                assert_eq!(pointer_id, PointerId::device(1));
+            }
+            _ => panic!("expected PointerEvent::Scroll"),
+        }
+
+        let round_tripped = RawPointerEvent::from(event);
+        assert_eq!(round_tripped.kind, RawPointerEventKind::Scroll);
+        assert_eq!(round_tripped.scroll, delta);
+        assert_eq!(round_tripped.pointer_id, PointerId::device(1));
+    }
+
+    #[test]
+    fn click_sequence_continues_within_thresholds() {
+        let mut counter = ClickCounter::new();
+        assert_eq!(counter.on_down((0.0, 0.0), Duration::from_millis(0)), 1);
+        counter.on_up((0.0, 0.0), Duration::from_millis(10));
+        assert_eq!(counter.on_down((2.0, 2.0), Duration::from_millis(100)), 2);
+        counter.on_up((2.0, 2.0), Duration::from_millis(110));
+        assert_eq!(counter.on_down((2.0, 0.0), Duration::from_millis(200)), 3);
+    }
+
+    #[test]
+    fn click_sequence_resets_after_delay() {
+        let mut counter = ClickCounter::new();
+        counter.on_down((0.0, 0.0), Duration::from_millis(0));
+        counter.on_up((0.0, 0.0), Duration::from_millis(10));
+        assert_eq!(counter.on_down((0.0, 0.0), Duration::from_millis(1000)), 1);
+    }
+
+    #[test]
+    fn click_sequence_resets_after_moving_too_far() {
+        let mut counter = ClickCounter::new();
+        counter.on_down((0.0, 0.0), Duration::from_millis(0));
+        counter.on_up((0.0, 0.0), Duration::from_millis(10));
+        assert_eq!(counter.on_down((50.0, 50.0), Duration::from_millis(50)), 1);
+    }
+
+    #[test]
+    fn moving_past_click_distance_while_pressed_promotes_to_drag() {
+        let mut counter = ClickCounter::new();
+        counter.on_down((0.0, 0.0), Duration::from_millis(0));
+        assert!(!counter.is_drag());
+        counter.on_move((50.0, 50.0));
+        assert!(counter.is_drag());
+        let was_click = counter.on_up((50.0, 50.0), Duration::from_millis(10));
+        assert!(!was_click);
+    }
+
+    #[test]
+    fn small_movement_within_click_distance_stays_a_click() {
+        let mut counter = ClickCounter::new();
+        counter.on_down((0.0, 0.0), Duration::from_millis(0));
+        counter.on_move((1.0, 1.0));
+        assert!(!counter.is_drag());
+        let was_click = counter.on_up((1.0, 1.0), Duration::from_millis(10));
+        assert!(was_click);
+    }
 }